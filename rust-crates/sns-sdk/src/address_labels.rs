@@ -0,0 +1,175 @@
+use crate::constants::REVERSE_LOOKUP_CLASS;
+use crate::error::SnsError;
+use crate::non_blocking::fetcher::SnsAccountFetcher;
+use crate::parse_account::{parse_sns_account, ParsedSnsAccount};
+use solana_client::client_error::ClientError;
+use solana_program::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// The RPC client reports a missing account as an error rather than `Ok(None)`, so this is
+/// the only way to tell "no reverse record" apart from a genuine RPC failure.
+fn is_account_not_found(err: &ClientError) -> bool {
+    err.to_string().contains("AccountNotFound")
+}
+
+/// Derives the reverse-lookup `NameRecordHeader` PDA for `pubkey`, the same account
+/// [`parse_sns_account`] decodes into [`ParsedSnsAccount::ReverseRecord`].
+fn reverse_record_key(pubkey: &Pubkey) -> Pubkey {
+    let hashed_name = spl_name_service::utils::get_hashed_name(&pubkey.to_string());
+    spl_name_service::state::get_seeds_and_key(
+        &spl_name_service::ID,
+        hashed_name,
+        Some(&REVERSE_LOOKUP_CLASS),
+        None,
+    )
+    .0
+}
+
+/// A pubkey-to-label cache, seeded from a JSON file and lazily filled in by reverse
+/// lookups, so a session never resolves the same address twice once it has a label.
+/// Addresses without a reverse record are not cached.
+#[derive(Default)]
+pub struct AddressLabels {
+    labels: HashMap<Pubkey, String>,
+}
+
+impl AddressLabels {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seeds the cache from a JSON file mapping base58 pubkeys to labels, e.g.
+    /// `{ "5gk1...": "SNS Authority" }`.
+    pub fn import_labels(&mut self, path: impl AsRef<Path>) -> Result<(), SnsError> {
+        let raw = std::fs::read_to_string(path).map_err(SnsError::LabelsFile)?;
+        let parsed: HashMap<String, String> =
+            serde_json::from_str(&raw).map_err(SnsError::Json)?;
+
+        for (pubkey, label) in parsed {
+            let pubkey: Pubkey = pubkey.parse().map_err(|_| SnsError::InvalidPubkey)?;
+            self.labels.insert(pubkey, label);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the cached label for `pubkey`, if any, without performing a reverse lookup.
+    pub fn get(&self, pubkey: &Pubkey) -> Option<&str> {
+        self.labels.get(pubkey).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, pubkey: Pubkey, label: String) {
+        self.labels.insert(pubkey, label);
+    }
+
+    /// Resolves `pubkey` to a `.sol` domain, checking the label cache first and falling
+    /// back to a reverse lookup only on a cache miss. A domain found by the lookup is
+    /// memoized, so subsequent calls for that pubkey never round-trip to the RPC again.
+    /// Returns `None` if `pubkey` has no reverse record rather than treating that as an
+    /// error; that outcome is not cached, so a later call for the same pubkey looks it up
+    /// again.
+    pub async fn resolve_with_cache(
+        &mut self,
+        rpc_client: &impl SnsAccountFetcher,
+        pubkey: &Pubkey,
+    ) -> Result<Option<String>, SnsError> {
+        if let Some(label) = self.get(pubkey) {
+            return Ok(Some(label.to_owned()));
+        }
+
+        let reverse_key = reverse_record_key(pubkey);
+        let data = match rpc_client.get_account_data(&reverse_key).await {
+            Ok(data) => data,
+            Err(err) if is_account_not_found(&err) => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+
+        let domain = match parse_sns_account(&spl_name_service::ID, &data)? {
+            ParsedSnsAccount::ReverseRecord { name } => name,
+            _ => return Ok(None),
+        };
+
+        self.insert(*pubkey, domain.clone());
+        Ok(Some(domain))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::non_blocking::mock::MockRpcClient;
+
+    #[tokio::test]
+    async fn cache_hit_short_circuits_the_rpc_call() {
+        let mock = MockRpcClient::new();
+        let pubkey = Pubkey::new_unique();
+        let mut labels = AddressLabels::new();
+        labels.insert(pubkey, "cached.sol".to_string());
+
+        let result = labels.resolve_with_cache(&mock, &pubkey).await.unwrap();
+        assert_eq!(result, Some("cached.sol".to_string()));
+    }
+
+    #[tokio::test]
+    async fn missing_reverse_record_resolves_to_none() {
+        let mock = MockRpcClient::new();
+        let pubkey = Pubkey::new_unique();
+        let mut labels = AddressLabels::new();
+
+        let result = labels.resolve_with_cache(&mock, &pubkey).await.unwrap();
+        assert_eq!(result, None);
+    }
+
+    #[tokio::test]
+    async fn non_missing_rpc_error_propagates() {
+        let pubkey = Pubkey::new_unique();
+        let reverse_key = reverse_record_key(&pubkey);
+        let mut mock = MockRpcClient::new();
+        mock.set_error(reverse_key, "RpcError: node is unhealthy");
+        let mut labels = AddressLabels::new();
+
+        let result = labels.resolve_with_cache(&mock, &pubkey).await;
+        assert!(matches!(result, Err(SnsError::ClientError(_))));
+    }
+
+    #[test]
+    fn import_labels_loads_valid_json() {
+        let dir = std::env::temp_dir();
+        let path = dir.join("sns_address_labels_valid.json");
+        let pubkey = Pubkey::new_unique();
+        std::fs::write(&path, format!(r#"{{"{pubkey}": "SNS Authority"}}"#)).unwrap();
+
+        let mut labels = AddressLabels::new();
+        labels.import_labels(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(labels.get(&pubkey), Some("SNS Authority"));
+    }
+
+    #[test]
+    fn import_labels_rejects_invalid_json() {
+        let dir = std::env::temp_dir();
+        let path = dir.join("sns_address_labels_invalid_json.json");
+        std::fs::write(&path, "not json").unwrap();
+
+        let mut labels = AddressLabels::new();
+        let result = labels.import_labels(&path);
+        std::fs::remove_file(&path).ok();
+
+        assert!(matches!(result, Err(SnsError::Json(_))));
+    }
+
+    #[test]
+    fn import_labels_rejects_invalid_pubkey() {
+        let dir = std::env::temp_dir();
+        let path = dir.join("sns_address_labels_invalid_pubkey.json");
+        std::fs::write(&path, r#"{"not-a-pubkey": "SNS Authority"}"#).unwrap();
+
+        let mut labels = AddressLabels::new();
+        let result = labels.import_labels(&path);
+        std::fs::remove_file(&path).ok();
+
+        assert!(matches!(result, Err(SnsError::InvalidPubkey)));
+    }
+}
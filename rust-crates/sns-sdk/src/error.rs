@@ -0,0 +1,40 @@
+use solana_client::client_error::ClientError;
+use solana_client::nonblocking::pubsub_client::PubsubClientError;
+use solana_program::program_error::ProgramError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SnsError {
+    #[error("RPC client error: {0}")]
+    ClientError(#[from] ClientError),
+
+    #[error("websocket subscription error: {0}")]
+    Subscription(#[from] PubsubClientError),
+
+    #[error("subscription task ended before it could be confirmed")]
+    SubscriptionClosed,
+
+    #[error("failed to deserialize account data: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("failed to read labels file: {0}")]
+    LabelsFile(std::io::Error),
+
+    #[error("failed to unpack account data: {0}")]
+    ProgramError(#[from] ProgramError),
+
+    #[error("failed to parse JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("invalid pubkey")]
+    InvalidPubkey,
+
+    #[error("account does not match the expected sub-registrar discriminator")]
+    InvalidSubRegistrar,
+
+    #[error("account does not match the expected reverse record layout")]
+    InvalidReverseRecord,
+
+    #[error("account data could not be decoded from the subscription notification")]
+    InvalidAccountData,
+}
@@ -0,0 +1,4 @@
+pub mod address_labels;
+pub mod error;
+pub mod non_blocking;
+pub mod parse_account;
@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+use solana_client::client_error::Result as ClientResult;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
+
+/// Abstracts over the account-fetching calls the SDK needs from an RPC client, so functions
+/// like [`get_sub_registrar_info`](crate::non_blocking::subdomain::get_sub_registrar_info)
+/// can be exercised against canned data instead of a live network.
+///
+/// The real `RpcClient` implements this directly; tests reach for
+/// [`MockRpcClient`](crate::non_blocking::mock::MockRpcClient) instead.
+#[async_trait]
+pub trait SnsAccountFetcher {
+    async fn get_account_data(&self, pubkey: &Pubkey) -> ClientResult<Vec<u8>>;
+    async fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> ClientResult<Vec<Option<Account>>>;
+}
+
+#[async_trait]
+impl SnsAccountFetcher for RpcClient {
+    async fn get_account_data(&self, pubkey: &Pubkey) -> ClientResult<Vec<u8>> {
+        RpcClient::get_account_data(self, pubkey).await
+    }
+
+    async fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> ClientResult<Vec<Option<Account>>> {
+        RpcClient::get_multiple_accounts(self, pubkeys).await
+    }
+}
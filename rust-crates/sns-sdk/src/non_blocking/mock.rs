@@ -0,0 +1,167 @@
+use crate::non_blocking::fetcher::SnsAccountFetcher;
+use async_trait::async_trait;
+use solana_client::client_error::{ClientError, ClientErrorKind, Result as ClientResult};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
+use std::collections::HashMap;
+
+/// Canned response for a pubkey programmed into a [`MockRpcClient`].
+enum MockAccount {
+    Data(Vec<u8>),
+    Missing,
+    Error(String),
+}
+
+/// An in-memory [`SnsAccountFetcher`] that returns canned account bytes keyed by pubkey.
+///
+/// Accounts not programmed via [`set_account`](Self::set_account) or
+/// [`set_missing`](Self::set_missing) behave as missing, matching the real RPC's behavior
+/// for an unknown pubkey.
+#[derive(Default)]
+pub struct MockRpcClient {
+    accounts: HashMap<Pubkey, MockAccount>,
+}
+
+impl MockRpcClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Programs `pubkey` to return `data` from `get_account_data`/`get_multiple_accounts`.
+    pub fn set_account(&mut self, pubkey: Pubkey, data: Vec<u8>) {
+        self.accounts.insert(pubkey, MockAccount::Data(data));
+    }
+
+    /// Programs `pubkey` to behave as though the account does not exist.
+    pub fn set_missing(&mut self, pubkey: Pubkey) {
+        self.accounts.insert(pubkey, MockAccount::Missing);
+    }
+
+    /// Programs `pubkey` to fail with a generic RPC error, distinct from "account not
+    /// found", so tests can exercise error propagation that isn't a missing-account miss.
+    pub fn set_error(&mut self, pubkey: Pubkey, message: impl Into<String>) {
+        self.accounts.insert(pubkey, MockAccount::Error(message.into()));
+    }
+
+    fn missing_account_error(pubkey: &Pubkey) -> ClientError {
+        ClientError::from(ClientErrorKind::Custom(format!(
+            "AccountNotFound: pubkey {pubkey} does not have any account data"
+        )))
+    }
+}
+
+#[async_trait]
+impl SnsAccountFetcher for MockRpcClient {
+    async fn get_account_data(&self, pubkey: &Pubkey) -> ClientResult<Vec<u8>> {
+        match self.accounts.get(pubkey) {
+            Some(MockAccount::Data(data)) => Ok(data.clone()),
+            Some(MockAccount::Missing) | None => Err(Self::missing_account_error(pubkey)),
+            Some(MockAccount::Error(message)) => {
+                Err(ClientError::from(ClientErrorKind::Custom(message.clone())))
+            }
+        }
+    }
+
+    async fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> ClientResult<Vec<Option<Account>>> {
+        pubkeys
+            .iter()
+            .map(|pubkey| match self.accounts.get(pubkey) {
+                Some(MockAccount::Data(data)) => Ok(Some(Account {
+                    data: data.clone(),
+                    ..Account::default()
+                })),
+                Some(MockAccount::Error(message)) => {
+                    Err(ClientError::from(ClientErrorKind::Custom(message.clone())))
+                }
+                Some(MockAccount::Missing) | None => Ok(None),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::non_blocking::subdomain::{
+        get_sub_registrar_info, get_sub_registrar_infos, Registrar, SubRegistrarAccountTag,
+        SUB_REGISTRAR_PROGRAM_ID,
+    };
+    use crate::error::SnsError;
+
+    #[tokio::test]
+    async fn missing_registrar_surfaces_as_client_error() {
+        let mock = MockRpcClient::new();
+        let result = get_sub_registrar_info(&mock, "example").await;
+        assert!(matches!(result, Err(SnsError::ClientError(_))));
+    }
+
+    #[tokio::test]
+    async fn wrong_discriminator_is_rejected() {
+        let mut mock = MockRpcClient::new();
+        let key = crate::derivation::get_domain_key("example").unwrap();
+        let registrar_key = crate::non_blocking::subdomain::Registrar::find_key(
+            &key,
+            &crate::non_blocking::subdomain::SUB_REGISTRAR_PROGRAM_ID,
+        )
+        .0;
+        mock.set_account(registrar_key, vec![SubRegistrarAccountTag::Registrar as u8 + 1]);
+
+        let result = get_sub_registrar_info(&mock, "example").await;
+        assert!(matches!(result, Err(SnsError::InvalidSubRegistrar)));
+    }
+
+    #[tokio::test]
+    async fn empty_account_data_is_rejected() {
+        let mut mock = MockRpcClient::new();
+        let key = crate::derivation::get_domain_key("example").unwrap();
+        let registrar_key = crate::non_blocking::subdomain::Registrar::find_key(
+            &key,
+            &crate::non_blocking::subdomain::SUB_REGISTRAR_PROGRAM_ID,
+        )
+        .0;
+        mock.set_account(registrar_key, vec![]);
+
+        let result = get_sub_registrar_info(&mock, "example").await;
+        assert!(matches!(result, Err(SnsError::InvalidSubRegistrar)));
+    }
+
+    fn registrar_key(domain: &str) -> Pubkey {
+        let key = crate::derivation::get_domain_key(domain).unwrap();
+        Registrar::find_key(&key, &SUB_REGISTRAR_PROGRAM_ID).0
+    }
+
+    /// A minimal well-formed sub-registrar account: the `Registrar` discriminator followed
+    /// by enough zeroed bytes for every field to decode to its default value.
+    fn registrar_account_bytes() -> Vec<u8> {
+        let mut data = vec![SubRegistrarAccountTag::Registrar as u8];
+        data.extend(std::iter::repeat(0u8).take(256));
+        data
+    }
+
+    #[tokio::test]
+    async fn get_sub_registrar_infos_preserves_order_for_missing_accounts() {
+        let mut mock = MockRpcClient::new();
+        mock.set_account(registrar_key("beta"), registrar_account_bytes());
+
+        let result = get_sub_registrar_infos(&mock, &["alpha", "beta", "gamma"])
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 3);
+        assert!(result[0].is_none());
+        assert!(result[1].is_some());
+        assert!(result[2].is_none());
+    }
+
+    #[tokio::test]
+    async fn get_sub_registrar_infos_rejects_wrong_discriminator() {
+        let mut mock = MockRpcClient::new();
+        mock.set_account(
+            registrar_key("beta"),
+            vec![SubRegistrarAccountTag::Registrar as u8 + 1],
+        );
+
+        let result = get_sub_registrar_infos(&mock, &["alpha", "beta"]).await;
+        assert!(matches!(result, Err(SnsError::InvalidSubRegistrar)));
+    }
+}
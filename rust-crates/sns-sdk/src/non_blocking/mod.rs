@@ -0,0 +1,4 @@
+pub mod fetcher;
+pub mod mock;
+pub mod subdomain;
+pub mod subscription;
@@ -1,23 +1,88 @@
+use crate::address_labels::AddressLabels;
 use crate::derivation::get_domain_key;
 use crate::error::SnsError;
+use crate::non_blocking::fetcher::SnsAccountFetcher;
 use borsh::BorshDeserialize;
-use solana_client::nonblocking::rpc_client::RpcClient;
 
 pub use sub_registrar::state::registry::Registrar;
 pub use sub_registrar::state::Tag as SubRegistrarAccountTag;
 pub use sub_registrar::ID as SUB_REGISTRAR_PROGRAM_ID;
 
+/// Fetches and decodes the sub-registrar for `domain`.
+///
+/// Takes `&impl SnsAccountFetcher` rather than `&RpcClient` directly so the tag-validation
+/// and deserialization logic can be exercised against a [`MockRpcClient`](crate::non_blocking::mock::MockRpcClient)
+/// in tests, without a live network.
 pub async fn get_sub_registrar_info(
-    rpc_client: &RpcClient,
+    rpc_client: &impl SnsAccountFetcher,
     domain: &str,
 ) -> Result<Registrar, SnsError> {
     let key = get_domain_key(domain)?;
     let registrar_key = Registrar::find_key(&key, &SUB_REGISTRAR_PROGRAM_ID).0;
     let account = rpc_client.get_account_data(&registrar_key).await?;
     let expected_tag = SubRegistrarAccountTag::Registrar;
-    if account[0] != expected_tag as u8 {
+    if account.first() != Some(&(expected_tag as u8)) {
         return Err(SnsError::InvalidSubRegistrar);
     }
     let result = Registrar::deserialize(&mut (&account as &[u8]))?;
     Ok(result)
 }
+
+/// Fetches the sub-registrar for `domain`, like [`get_sub_registrar_info`], but resolves
+/// its authority to a `.sol` label using `labels`, avoiding a redundant reverse lookup if
+/// the authority has already been resolved this session. The label is `None` if the
+/// authority has no reverse record.
+pub async fn get_sub_registrar_info_labeled(
+    rpc_client: &impl SnsAccountFetcher,
+    labels: &mut AddressLabels,
+    domain: &str,
+) -> Result<(Registrar, Option<String>), SnsError> {
+    let registrar = get_sub_registrar_info(rpc_client, domain).await?;
+    let authority_label = labels
+        .resolve_with_cache(rpc_client, &registrar.authority)
+        .await?;
+    Ok((registrar, authority_label))
+}
+
+/// The `getMultipleAccounts` RPC method caps the number of pubkeys per request at 100.
+const GET_MULTIPLE_ACCOUNTS_BATCH_SIZE: usize = 100;
+
+/// Batched variant of [`get_sub_registrar_info`].
+///
+/// Derives the `Registrar` PDA for every domain up front and resolves them with as few
+/// `getMultipleAccounts` round-trips as possible, chunking requests at the RPC limit. The
+/// output preserves the order of `domains` so callers can zip results back to their inputs;
+/// a domain whose registrar account does not exist maps to `None`, while an account that
+/// exists but fails the discriminator check returns [`SnsError::InvalidSubRegistrar`], same
+/// as [`get_sub_registrar_info`].
+pub async fn get_sub_registrar_infos(
+    rpc_client: &impl SnsAccountFetcher,
+    domains: &[&str],
+) -> Result<Vec<Option<Registrar>>, SnsError> {
+    let registrar_keys = domains
+        .iter()
+        .map(|domain| {
+            let key = get_domain_key(domain)?;
+            Ok(Registrar::find_key(&key, &SUB_REGISTRAR_PROGRAM_ID).0)
+        })
+        .collect::<Result<Vec<_>, SnsError>>()?;
+
+    let expected_tag = SubRegistrarAccountTag::Registrar as u8;
+    let mut registrars = Vec::with_capacity(registrar_keys.len());
+
+    for chunk in registrar_keys.chunks(GET_MULTIPLE_ACCOUNTS_BATCH_SIZE) {
+        let accounts = rpc_client.get_multiple_accounts(chunk).await?;
+        for account in accounts {
+            let registrar = match account {
+                None => None,
+                Some(account) if account.data.first() == Some(&expected_tag) => {
+                    Some(Registrar::deserialize(&mut account.data.as_slice())?)
+                }
+                Some(_) => return Err(SnsError::InvalidSubRegistrar),
+            };
+            registrars.push(registrar);
+        }
+    }
+
+    Ok(registrars)
+}
@@ -0,0 +1,148 @@
+use crate::derivation::get_domain_key;
+use crate::error::SnsError;
+use crate::non_blocking::subdomain::{Registrar, SubRegistrarAccountTag, SUB_REGISTRAR_PROGRAM_ID};
+use crate::parse_account::{parse_sns_account, ParsedSnsAccount};
+use borsh::BorshDeserialize;
+use futures::StreamExt;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::nonblocking::pubsub_client::PubsubClient;
+use solana_client::rpc_config::RpcAccountInfoConfig;
+use solana_program::pubkey::Pubkey;
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinHandle;
+
+/// An open `accountSubscribe` subscription.
+///
+/// The `PubsubClient` and the notification stream it hands back borrow from each other for
+/// the life of the subscription, so both are driven together on a dedicated background task
+/// instead of being leaked for the life of the process. Call [`Self::next`] to await the
+/// next decoded update and [`Self::shutdown`] to unsubscribe and close the connection.
+pub struct AccountSubscription<T> {
+    receiver: mpsc::Receiver<Result<T, SnsError>>,
+    cancel: oneshot::Sender<()>,
+    task: JoinHandle<()>,
+}
+
+impl<T> AccountSubscription<T> {
+    /// Awaits the next decoded notification, or `None` once the subscription has ended.
+    pub async fn next(&mut self) -> Option<Result<T, SnsError>> {
+        self.receiver.recv().await
+    }
+
+    /// Unsubscribes and closes the underlying websocket connection.
+    pub async fn shutdown(self) {
+        let _ = self.cancel.send(());
+        let _ = self.task.await;
+    }
+}
+
+/// Opens an `accountSubscribe` stream on the sub-registrar PDA for `domain`, yielding a
+/// decoded [`Registrar`] on every account write.
+///
+/// Derives the registrar PDA the same way [`get_sub_registrar_info`](crate::non_blocking::subdomain::get_sub_registrar_info)
+/// does, then replays its tag-validation and Borsh deserialization on each notification so
+/// a malformed update surfaces as [`SnsError::InvalidSubRegistrar`] instead of panicking.
+pub async fn subscribe_sub_registrar(
+    ws_url: &str,
+    domain: &str,
+) -> Result<AccountSubscription<Registrar>, SnsError> {
+    let key = get_domain_key(domain)?;
+    let registrar_key = Registrar::find_key(&key, &SUB_REGISTRAR_PROGRAM_ID).0;
+    subscribe_domain_key(ws_url, registrar_key, |data| {
+        let expected_tag = SubRegistrarAccountTag::Registrar as u8;
+        if data.first() != Some(&expected_tag) {
+            return Err(SnsError::InvalidSubRegistrar);
+        }
+        Registrar::deserialize(&mut &data[..]).map_err(|_| SnsError::InvalidSubRegistrar)
+    })
+    .await
+}
+
+/// Generic version of [`subscribe_sub_registrar`] that yields the decoded
+/// [`ParsedSnsAccount`] for `domain`'s name record on every write, for callers watching a
+/// domain whose account type isn't known up front.
+pub async fn subscribe_domain(
+    ws_url: &str,
+    domain: &str,
+) -> Result<AccountSubscription<ParsedSnsAccount>, SnsError> {
+    let key = get_domain_key(domain)?;
+    subscribe_domain_key(ws_url, key, |data| parse_sns_account(&spl_name_service::ID, &data)).await
+}
+
+async fn subscribe_domain_key<T, F>(
+    ws_url: &str,
+    key: Pubkey,
+    decode: F,
+) -> Result<AccountSubscription<T>, SnsError>
+where
+    T: Send + 'static,
+    F: Fn(Vec<u8>) -> Result<T, SnsError> + Send + 'static,
+{
+    let ws_url = ws_url.to_owned();
+    let (tx, rx) = mpsc::channel(16);
+    let (cancel_tx, mut cancel_rx) = oneshot::channel();
+    let (ready_tx, ready_rx) = oneshot::channel();
+
+    // `account_subscribe` hands back a stream that borrows the `PubsubClient` it was called
+    // on, so the client and the stream must live out their entire lifetime together. Rather
+    // than leaking the client to manufacture a `'static` borrow, both are kept as locals of
+    // this single task: the client is created here, the stream borrows it for the task's
+    // whole run, and neither is ever moved out, so the borrow never outlives its owner.
+    let task: JoinHandle<()> = tokio::spawn(async move {
+        let client = match PubsubClient::new(&ws_url).await {
+            Ok(client) => client,
+            Err(err) => {
+                let _ = ready_tx.send(Err(SnsError::from(err)));
+                return;
+            }
+        };
+
+        let config = RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..RpcAccountInfoConfig::default()
+        };
+        let (mut account_stream, unsubscribe) = match client.account_subscribe(&key, Some(config)).await
+        {
+            Ok(pair) => pair,
+            Err(err) => {
+                let _ = ready_tx.send(Err(SnsError::from(err)));
+                return;
+            }
+        };
+
+        if ready_tx.send(Ok(())).is_err() {
+            unsubscribe().await;
+            return;
+        }
+
+        loop {
+            tokio::select! {
+                _ = &mut cancel_rx => break,
+                response = account_stream.next() => {
+                    let Some(response) = response else { break };
+                    let decoded = response
+                        .value
+                        .data
+                        .decode()
+                        .ok_or(SnsError::InvalidAccountData)
+                        .and_then(&decode);
+                    if tx.send(decoded).await.is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+
+        unsubscribe().await;
+    });
+
+    match ready_rx.await {
+        Ok(Ok(())) => Ok(AccountSubscription {
+            receiver: rx,
+            cancel: cancel_tx,
+            task,
+        }),
+        Ok(Err(err)) => Err(err),
+        Err(_) => Err(SnsError::SubscriptionClosed),
+    }
+}
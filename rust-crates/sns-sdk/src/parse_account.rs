@@ -0,0 +1,103 @@
+use crate::constants::REVERSE_LOOKUP_CLASS;
+use crate::error::SnsError;
+use crate::non_blocking::subdomain::{Registrar, SubRegistrarAccountTag, SUB_REGISTRAR_PROGRAM_ID};
+use borsh::BorshDeserialize;
+use serde::Serialize;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use spl_name_service::state::NameRecordHeader;
+
+/// A decoded SNS account, tagged by the program that owns it.
+///
+/// Every variant derives `Serialize` so callers can dump any SNS account to JSON sight
+/// unseen.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", content = "info")]
+pub enum ParsedSnsAccount {
+    Registrar(Registrar),
+    NameRecordHeader {
+        owner: Pubkey,
+        class: Pubkey,
+        parent_name: Pubkey,
+    },
+    ReverseRecord {
+        name: String,
+    },
+    Unknown,
+}
+
+/// Parses raw account `data` owned by `program_id` into a [`ParsedSnsAccount`].
+///
+/// Sub-registrar accounts are recognized by `program_id` and validated with the same
+/// discriminator check [`get_sub_registrar_info`](crate::non_blocking::subdomain::get_sub_registrar_info)
+/// already performs; name service accounts are recognized by their `class`, which
+/// distinguishes a plain `NameRecordHeader` from a reverse-lookup record. Anything else
+/// comes back as `Unknown` rather than erroring, since callers may hand this function
+/// accounts they merely suspect are SNS-related.
+pub fn parse_sns_account(program_id: &Pubkey, data: &[u8]) -> Result<ParsedSnsAccount, SnsError> {
+    if *program_id == SUB_REGISTRAR_PROGRAM_ID {
+        if data.first() != Some(&(SubRegistrarAccountTag::Registrar as u8)) {
+            return Err(SnsError::InvalidSubRegistrar);
+        }
+        let registrar = Registrar::deserialize(&mut &data[..])?;
+        return Ok(ParsedSnsAccount::Registrar(registrar));
+    }
+
+    if *program_id == spl_name_service::ID {
+        let header = NameRecordHeader::unpack_from_slice(data)?;
+        if header.class == REVERSE_LOOKUP_CLASS {
+            let name_data = &data[NameRecordHeader::LEN..];
+            let name = String::try_from_slice(name_data).map_err(|_| SnsError::InvalidReverseRecord)?;
+            return Ok(ParsedSnsAccount::ReverseRecord { name });
+        }
+        return Ok(ParsedSnsAccount::NameRecordHeader {
+            owner: header.owner,
+            class: header.class,
+            parent_name: header.parent_name,
+        });
+    }
+
+    Ok(ParsedSnsAccount::Unknown)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use borsh::BorshSerialize;
+
+    fn name_record_header_bytes(class: Pubkey) -> Vec<u8> {
+        let mut data = vec![0u8; NameRecordHeader::LEN];
+        data[0..32].copy_from_slice(&Pubkey::new_unique().to_bytes());
+        data[32..64].copy_from_slice(&Pubkey::new_unique().to_bytes());
+        data[64..96].copy_from_slice(&class.to_bytes());
+        data
+    }
+
+    #[test]
+    fn dispatches_to_name_record_header() {
+        let data = name_record_header_bytes(Pubkey::new_unique());
+        let parsed = parse_sns_account(&spl_name_service::ID, &data).unwrap();
+        assert!(matches!(parsed, ParsedSnsAccount::NameRecordHeader { .. }));
+    }
+
+    #[test]
+    fn dispatches_to_reverse_record() {
+        let mut data = name_record_header_bytes(REVERSE_LOOKUP_CLASS);
+        data.extend(String::from("example").try_to_vec().unwrap());
+        let parsed = parse_sns_account(&spl_name_service::ID, &data).unwrap();
+        assert!(matches!(parsed, ParsedSnsAccount::ReverseRecord { name } if name == "example"));
+    }
+
+    #[test]
+    fn dispatches_to_invalid_sub_registrar() {
+        let data = vec![SubRegistrarAccountTag::Registrar as u8 + 1];
+        let result = parse_sns_account(&SUB_REGISTRAR_PROGRAM_ID, &data);
+        assert!(matches!(result, Err(SnsError::InvalidSubRegistrar)));
+    }
+
+    #[test]
+    fn dispatches_to_unknown_for_unrecognized_owner() {
+        let parsed = parse_sns_account(&Pubkey::new_unique(), &[]).unwrap();
+        assert!(matches!(parsed, ParsedSnsAccount::Unknown));
+    }
+}